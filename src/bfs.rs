@@ -1,18 +1,19 @@
-use crate::graph::{Graph, NodeIndex, Successors};
+use crate::bitvector::BitVector;
+use crate::graph::{Graph, NodeIndex, Neighbors};
 use std::collections::{HashSet, VecDeque};
 
-impl<T> Graph<T> {
+impl<T, E> Graph<T, E> {
     /// Creates a breadth-first iterator over the graph, starting from `source`.
     ///
     /// This iterator returns sets of nodes, grouped by their depth from the root node. To iterate
     /// over a sequence of individual nodes, use the [`flatten`] method on the BFS iterator.
     ///
     /// [`flatten`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.flatten
-    pub fn bfs(&self, source: NodeIndex) -> Bfs<T> {
+    pub fn bfs(&self, source: NodeIndex) -> Bfs<T, E> {
         use maplit::hashset;
         let mut bfs = Bfs {
             graph: self,
-            visited: self.nodes.iter().map(|_| false).collect(),
+            visited: BitVector::with_capacity(self.nodes.len()),
             queue: VecDeque::new(),
         };
         bfs.queue.push_back(hashset![source]);
@@ -21,24 +22,24 @@ impl<T> Graph<T> {
 }
 
 /// A breadth-first iterator over a graph.
-pub struct Bfs<'g, T> {
-    graph: &'g Graph<T>,
-    visited: Vec<bool>,
+pub struct Bfs<'g, T, E> {
+    graph: &'g Graph<T, E>,
+    visited: BitVector,
     queue: VecDeque<HashSet<NodeIndex>>,
 }
 
-impl<'g, T> Bfs<'g, T> {
+impl<'g, T, E> Bfs<'g, T, E> {
     fn is_visited(&self, node: NodeIndex) -> bool {
-        self.visited[node.0]
+        self.visited.contains(node.0)
     }
 
-    fn visit(&mut self, node: NodeIndex) -> Successors<'g, T> {
-        self.visited[node.0] = true;
+    fn visit(&mut self, node: NodeIndex) -> Neighbors<'g, T, E> {
+        self.visited.insert(node.0);
         self.graph.successors(node)
     }
 }
 
-impl<'g, T> Iterator for Bfs<'g, T> {
+impl<'g, T, E> Iterator for Bfs<'g, T, E> {
     type Item = HashSet<NodeIndex>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -86,7 +87,7 @@ mod test {
 
     #[test]
     fn bfs_one_node() {
-        let mut graph = Graph::new();
+        let mut graph = Graph::<i32, ()>::new();
         graph.add_node(1);
         assert_eq!(bfs_values(&graph, 1), [hashset![1]]);
     }
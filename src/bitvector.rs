@@ -0,0 +1,59 @@
+/// A compact set of small non-negative integers, backed by a bit-per-element `Vec<u64>`.
+///
+/// Used as the visited store for graph traversals, where it replaces a `Vec<bool>` to cut memory
+/// by 8x and keep membership tests cache-friendly on large graphs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct BitVector {
+    words: Vec<u64>,
+}
+
+impl BitVector {
+    /// Creates an empty bit vector able to hold indices in `0..capacity`.
+    ///
+    /// The vector never grows: `insert` and `contains` panic on an index past its last word.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        BitVector {
+            words: vec![0; capacity.div_ceil(64)],
+        }
+    }
+
+    /// Inserts `index` into the set, returning whether the bit was previously unset.
+    pub(crate) fn insert(&mut self, index: usize) -> bool {
+        let word = index >> 6;
+        let mask = 1u64 << (index & 63);
+        let changed = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        changed
+    }
+
+    /// Returns whether `index` is present in the set.
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        let word = index >> 6;
+        let mask = 1u64 << (index & 63);
+        self.words[word] & mask != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_reports_changes() {
+        let mut bits = BitVector::with_capacity(128);
+        assert!(bits.insert(3));
+        assert!(!bits.insert(3));
+        assert!(bits.insert(100));
+    }
+
+    #[test]
+    fn contains_matches_inserts() {
+        let mut bits = BitVector::with_capacity(128);
+        bits.insert(0);
+        bits.insert(64);
+        assert!(bits.contains(0));
+        assert!(bits.contains(64));
+        assert!(!bits.contains(1));
+        assert!(!bits.contains(63));
+    }
+}
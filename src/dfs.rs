@@ -1,37 +1,62 @@
-use crate::graph::{Graph, NodeIndex};
+use crate::bitvector::BitVector;
+use crate::graph::{Graph, Neighbors, NodeIndex};
 
-impl<T> Graph<T> {
+impl<T, E> Graph<T, E> {
     /// Creates a depth-first iterator over the graph, starting from `source`.
-    pub fn dfs(&self, source: NodeIndex) -> Dfs<T> {
+    pub fn dfs(&self, source: NodeIndex) -> Dfs<T, E> {
         Dfs {
             graph: self,
-            visited: self.nodes.iter().map(|_| false).collect(),
+            visited: BitVector::with_capacity(self.nodes.len()),
             stack: vec![source],
         }
     }
+
+    /// Creates a depth-first iterator over the *edges* reachable from `source`, classifying each
+    /// one it yields as a [tree edge or a back edge](EdgeKind). An edge leading to a node that is
+    /// already fully explored (a forward or cross edge) is skipped and not yielded.
+    ///
+    /// The traversal uses three-colour marking: a node is grey while it is on the active recursion
+    /// path and black once fully explored. Stepping to a grey node is a back edge, which means the
+    /// graph contains a cycle reachable from `source`; see [`cyclic`](DfsEdges::cyclic).
+    pub fn dfs_edges(&self, source: NodeIndex) -> DfsEdges<T, E> {
+        let mut colors: Vec<Color> = self.nodes.iter().map(|_| Color::White).collect();
+        colors[source.0] = Color::Gray;
+        DfsEdges {
+            graph: self,
+            colors: colors,
+            stack: vec![(source, self.successors(source))],
+            cyclic: false,
+        }
+    }
+
+    /// Returns whether the graph contains a cycle reachable from `source`.
+    pub fn is_cyclic(&self, source: NodeIndex) -> bool {
+        self.dfs_edges(source)
+            .any(|(_, _, kind)| kind == EdgeKind::Back)
+    }
 }
 
 /// A depth-first iterator over a graph.
-pub struct Dfs<'g, T> {
-    graph: &'g Graph<T>,
-    visited: Vec<bool>,
+pub struct Dfs<'g, T, E> {
+    graph: &'g Graph<T, E>,
+    visited: BitVector,
     stack: Vec<NodeIndex>,
 }
 
-impl<'g, T> Dfs<'g, T> {
+impl<'g, T, E> Dfs<'g, T, E> {
     fn is_visited(&self, node: NodeIndex) -> bool {
-        self.visited[node.0]
+        self.visited.contains(node.0)
     }
 
     fn visit(&mut self, node: NodeIndex) {
-        self.visited[node.0] = true;
+        self.visited.insert(node.0);
         for next in self.graph.successors(node) {
             self.stack.push(next);
         }
     }
 }
 
-impl<'g, T> Iterator for Dfs<'g, T> {
+impl<'g, T, E> Iterator for Dfs<'g, T, E> {
     type Item = NodeIndex;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -52,6 +77,79 @@ impl<'g, T> Iterator for Dfs<'g, T> {
     }
 }
 
+/// The classification of an edge visited by a [`DfsEdges`] traversal.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum EdgeKind {
+    /// An edge leading to a node not yet discovered; these edges form the DFS tree.
+    Tree,
+    /// An edge leading back to a node still on the active recursion path, revealing a cycle.
+    Back,
+}
+
+/// The exploration state of a node during a [`DfsEdges`] traversal.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Color {
+    /// Not yet discovered.
+    White,
+    /// Discovered and on the active recursion path.
+    Gray,
+    /// Fully explored.
+    Black,
+}
+
+/// A depth-first iterator over a graph's edges, yielding `(source, target, kind)` triples.
+pub struct DfsEdges<'g, T, E> {
+    graph: &'g Graph<T, E>,
+    colors: Vec<Color>,
+    stack: Vec<(NodeIndex, Neighbors<'g, T, E>)>,
+    cyclic: bool,
+}
+
+impl<'g, T, E> DfsEdges<'g, T, E> {
+    /// Returns whether a back edge has been observed so far.
+    ///
+    /// Once the traversal has run to completion this reports whether the reachable subgraph
+    /// contains a cycle.
+    pub fn cyclic(&self) -> bool {
+        self.cyclic
+    }
+}
+
+impl<'g, T, E> Iterator for DfsEdges<'g, T, E> {
+    type Item = (NodeIndex, NodeIndex, EdgeKind);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, next) = {
+                let (node, neighbors) = self.stack.last_mut()?;
+                match neighbors.next() {
+                    Some(next) => (*node, Some(next)),
+                    None => (*node, None),
+                }
+            };
+            match next {
+                None => {
+                    self.colors[node.0] = Color::Black;
+                    self.stack.pop();
+                }
+                Some(next) => match self.colors[next.0] {
+                    Color::White => {
+                        self.colors[next.0] = Color::Gray;
+                        let neighbors = self.graph.successors(next);
+                        self.stack.push((next, neighbors));
+                        return Some((node, next, EdgeKind::Tree));
+                    }
+                    Color::Gray => {
+                        self.cyclic = true;
+                        return Some((node, next, EdgeKind::Back));
+                    }
+                    Color::Black => continue,
+                },
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -69,7 +167,7 @@ mod test {
 
     #[test]
     fn dfs_one_node() {
-        let mut graph = Graph::new();
+        let mut graph = Graph::<i32, ()>::new();
         let node = graph.add_node(1);
         assert_eq!(graph.dfs(node).collect::<Vec<_>>(), [node]);
     }
@@ -105,4 +203,36 @@ mod test {
         };
         assert_eq!(dfs_values(&graph, 'A'), ['A', 'B', 'D', 'F', 'E', 'C', 'G']);
     }
+
+    #[test]
+    fn acyclic_graph_has_no_cycle() {
+        let graph = graph![1 -> 2, 2 -> 3, 1 -> 3];
+        let one = graph.find_node(&1).unwrap();
+        assert!(!graph.is_cyclic(one));
+    }
+
+    #[test]
+    fn self_loop_is_cyclic() {
+        let graph = graph![1 -> 1];
+        let one = graph.find_node(&1).unwrap();
+        assert!(graph.is_cyclic(one));
+    }
+
+    #[test]
+    fn cycle_is_detected_as_back_edge() {
+        let graph = graph![1 -> 2, 2 -> 3, 3 -> 1];
+        let one = graph.find_node(&1).unwrap();
+        assert!(graph.is_cyclic(one));
+    }
+
+    #[test]
+    fn dfs_edges_classifies_tree_and_back() {
+        let graph = graph![1 -> 2, 2 -> 3, 3 -> 1];
+        let one = graph.find_node(&1).unwrap();
+        let kinds: Vec<_> = graph.dfs_edges(one).map(|(_, _, kind)| kind).collect();
+        assert_eq!(
+            kinds,
+            [EdgeKind::Tree, EdgeKind::Tree, EdgeKind::Back]
+        );
+    }
 }
@@ -0,0 +1,154 @@
+use crate::graph::{Graph, NodeIndex};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Add;
+
+impl<T, E> Graph<T, E>
+where
+    E: Add<Output = E> + Ord + Copy + Default,
+{
+    /// Computes the shortest distance from `source` to every reachable node.
+    ///
+    /// Distances are summed from the edge weights using [`Add`], starting from `E::default()` at
+    /// the source. The returned map contains an entry for each node reachable from `source`;
+    /// unreachable nodes are absent.
+    ///
+    /// # Preconditions
+    ///
+    /// Every edge weight must be non-negative (that is, at least `E::default()`). Dijkstra's
+    /// algorithm gives incorrect results in the presence of negative weights.
+    pub fn dijkstra(&self, source: NodeIndex) -> HashMap<NodeIndex, E> {
+        self.dijkstra_with_predecessors(source).0
+    }
+
+    /// Computes the shortest path from `source` to `target` as a sequence of node indices.
+    ///
+    /// The path starts at `source` and ends at `target`. If `target` is not reachable from
+    /// `source` the returned vector is empty; a path from a node to itself is just `[source]`.
+    /// See [`dijkstra`](Self::dijkstra) for the weight preconditions.
+    pub fn shortest_path(&self, source: NodeIndex, target: NodeIndex) -> Vec<NodeIndex> {
+        let (distances, predecessors) = self.dijkstra_with_predecessors(source);
+        if !distances.contains_key(&target) {
+            return vec![];
+        }
+        let mut path = vec![target];
+        let mut node = target;
+        while node != source {
+            node = predecessors[&node];
+            path.push(node);
+        }
+        path.reverse();
+        path
+    }
+
+    /// Runs Dijkstra's algorithm, returning both the distance map and a map from each reachable
+    /// node to its predecessor on a shortest path from `source`.
+    fn dijkstra_with_predecessors(
+        &self,
+        source: NodeIndex,
+    ) -> (HashMap<NodeIndex, E>, HashMap<NodeIndex, NodeIndex>) {
+        let mut distances = HashMap::new();
+        let mut predecessors = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        distances.insert(source, E::default());
+        heap.push(MinScored(E::default(), source));
+
+        while let Some(MinScored(distance, node)) = heap.pop() {
+            // Lazy deletion: a stale heap entry is ignored once a shorter distance is known.
+            if distances.get(&node).is_some_and(|&best| distance > best) {
+                continue;
+            }
+            for (target, &weight) in self.successors_with_values(node) {
+                debug_assert!(
+                    weight >= E::default(),
+                    "dijkstra requires non-negative edge weights"
+                );
+                let next = distance + weight;
+                if distances.get(&target).is_none_or(|&best| next < best) {
+                    distances.insert(target, next);
+                    predecessors.insert(target, node);
+                    heap.push(MinScored(next, target));
+                }
+            }
+        }
+
+        (distances, predecessors)
+    }
+}
+
+/// A key/value pair ordered by the key, but with the ordering reversed.
+///
+/// [`BinaryHeap`] is a max-heap, so wrapping `(distance, node)` in a `MinScored` makes the entry
+/// with the *smallest* distance pop first.
+#[derive(Copy, Clone, Debug)]
+struct MinScored<K, T>(K, T);
+
+impl<K: PartialEq, T> PartialEq for MinScored<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq, T> Eq for MinScored<K, T> {}
+
+impl<K: Ord, T> PartialOrd for MinScored<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for MinScored<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::graph::Graph;
+
+    #[test]
+    fn dijkstra_single_node() {
+        let mut graph: Graph<i32, u32> = Graph::new();
+        let node = graph.add_node(0);
+        let distances = graph.dijkstra(node);
+        assert_eq!(distances[&node], 0);
+        assert_eq!(distances.len(), 1);
+    }
+
+    #[test]
+    fn dijkstra_prefers_shorter_path() {
+        let graph: Graph<i32, u32> = graph![
+            0 -> 1 : 1,
+            1 -> 2 : 1,
+            0 -> 2 : 5
+        ];
+        let source = graph.find_node(&0).unwrap();
+        let two = graph.find_node(&2).unwrap();
+        let distances = graph.dijkstra(source);
+        assert_eq!(distances[&two], 2);
+    }
+
+    #[test]
+    fn shortest_path_reconstructs_route() {
+        let graph: Graph<i32, u32> = graph![
+            0 -> 1 : 1,
+            1 -> 2 : 1,
+            0 -> 2 : 5
+        ];
+        let zero = graph.find_node(&0).unwrap();
+        let one = graph.find_node(&1).unwrap();
+        let two = graph.find_node(&2).unwrap();
+        assert_eq!(graph.shortest_path(zero, two), [zero, one, two]);
+        assert_eq!(graph.shortest_path(zero, zero), [zero]);
+    }
+
+    #[test]
+    fn shortest_path_unreachable_is_empty() {
+        let mut graph: Graph<i32, u32> = Graph::new();
+        let zero = graph.add_node(0);
+        let one = graph.add_node(1);
+        assert!(graph.shortest_path(zero, one).is_empty());
+    }
+}
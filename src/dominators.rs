@@ -0,0 +1,190 @@
+use crate::graph::{Graph, Neighbors, NodeIndex};
+use std::collections::HashMap;
+
+impl<T, E> Graph<T, E> {
+    /// Computes the dominator tree of the nodes reachable from `root`.
+    ///
+    /// A node *d* dominates a node *n* if every path from `root` to *n* passes through *d*. The
+    /// returned [`Dominators`] answers [`immediate_dominator`](Dominators::immediate_dominator)
+    /// and [`dominators`](Dominators::dominators) queries.
+    ///
+    /// This is the iterative Cooper–Harvey–Kennedy algorithm: nodes are visited in reverse
+    /// postorder and each node's immediate dominator is refined to a fixpoint by intersecting its
+    /// already-processed predecessors. Nodes unreachable from `root` are excluded.
+    pub fn dominators(&self, root: NodeIndex) -> Dominators {
+        let reverse_post_order = self.reverse_post_order(root);
+        let mut rpo_number = HashMap::new();
+        for (number, &node) in reverse_post_order.iter().enumerate() {
+            rpo_number.insert(node, number);
+        }
+
+        let mut immediate = HashMap::new();
+        immediate.insert(root, root);
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &node in reverse_post_order.iter().skip(1) {
+                let mut new_idom: Option<NodeIndex> = None;
+                for pred in self.predecessors(node) {
+                    // Predecessors whose idom is still undefined contribute nothing yet.
+                    if immediate.contains_key(&pred) {
+                        new_idom = Some(match new_idom {
+                            None => pred,
+                            Some(current) => intersect(&immediate, &rpo_number, current, pred),
+                        });
+                    }
+                }
+                if let Some(idom) = new_idom {
+                    if immediate.get(&node) != Some(&idom) {
+                        immediate.insert(node, idom);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        Dominators {
+            root: root,
+            immediate: immediate,
+        }
+    }
+
+    /// Returns the nodes reachable from `root` in reverse postorder, `root` first.
+    fn reverse_post_order(&self, root: NodeIndex) -> Vec<NodeIndex> {
+        let mut visited: Vec<bool> = self.nodes.iter().map(|_| false).collect();
+        let mut post_order = Vec::new();
+        let mut stack: Vec<(NodeIndex, Neighbors<T, E>)> = vec![(root, self.successors(root))];
+        visited[root.0] = true;
+
+        while !stack.is_empty() {
+            let (node, next) = {
+                let (node, neighbors) = stack.last_mut().unwrap();
+                (*node, neighbors.next())
+            };
+            match next {
+                Some(successor) => {
+                    if !visited[successor.0] {
+                        visited[successor.0] = true;
+                        let neighbors = self.successors(successor);
+                        stack.push((successor, neighbors));
+                    }
+                }
+                None => {
+                    post_order.push(node);
+                    stack.pop();
+                }
+            }
+        }
+
+        post_order.reverse();
+        post_order
+    }
+}
+
+/// Walks two fingers up the idom tree until they meet, always advancing the one that is deeper in
+/// reverse postorder (the higher-numbered one).
+fn intersect(
+    immediate: &HashMap<NodeIndex, NodeIndex>,
+    rpo_number: &HashMap<NodeIndex, usize>,
+    mut a: NodeIndex,
+    mut b: NodeIndex,
+) -> NodeIndex {
+    while a != b {
+        while rpo_number[&a] > rpo_number[&b] {
+            a = immediate[&a];
+        }
+        while rpo_number[&b] > rpo_number[&a] {
+            b = immediate[&b];
+        }
+    }
+    a
+}
+
+/// The dominator relation of a graph, as computed by [`Graph::dominators`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dominators {
+    root: NodeIndex,
+    immediate: HashMap<NodeIndex, NodeIndex>,
+}
+
+impl Dominators {
+    /// Returns the root the dominator tree was computed from.
+    pub fn root(&self) -> NodeIndex {
+        self.root
+    }
+
+    /// Returns the immediate dominator of `node`, or `None` for the root and for any node that is
+    /// unreachable from it.
+    pub fn immediate_dominator(&self, node: NodeIndex) -> Option<NodeIndex> {
+        if node == self.root {
+            None
+        } else {
+            self.immediate.get(&node).copied()
+        }
+    }
+
+    /// Returns an iterator over the dominators of `node`, from `node` itself up to the root.
+    ///
+    /// The iterator is empty if `node` is unreachable from the root.
+    pub fn dominators(&self, node: NodeIndex) -> DominatorsIter {
+        let reachable = node == self.root || self.immediate.contains_key(&node);
+        DominatorsIter {
+            dominators: self,
+            node: if reachable { Some(node) } else { None },
+        }
+    }
+}
+
+/// An iterator over the dominators of a node, yielded from the node up to the root.
+pub struct DominatorsIter<'d> {
+    dominators: &'d Dominators,
+    node: Option<NodeIndex>,
+}
+
+impl<'d> Iterator for DominatorsIter<'d> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node?;
+        self.node = self.dominators.immediate_dominator(node);
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::graph::Graph;
+
+    #[test]
+    fn linear_chain_dominators() {
+        let graph = graph![0 -> 1, 1 -> 2];
+        let root = graph.find_node(&0).unwrap();
+        let one = graph.find_node(&1).unwrap();
+        let two = graph.find_node(&2).unwrap();
+        let dominators = graph.dominators(root);
+        assert_eq!(dominators.immediate_dominator(root), None);
+        assert_eq!(dominators.immediate_dominator(one), Some(root));
+        assert_eq!(dominators.immediate_dominator(two), Some(one));
+        assert_eq!(dominators.dominators(two).collect::<Vec<_>>(), [two, one, root]);
+    }
+
+    #[test]
+    fn diamond_join_is_dominated_by_root() {
+        let graph = graph![0 -> 1, 0 -> 2, 1 -> 3, 2 -> 3];
+        let root = graph.find_node(&0).unwrap();
+        let join = graph.find_node(&3).unwrap();
+        let dominators = graph.dominators(root);
+        assert_eq!(dominators.immediate_dominator(join), Some(root));
+    }
+
+    #[test]
+    fn unreachable_node_is_excluded() {
+        let graph = graph![0 -> 1, 2 -> 1];
+        let root = graph.find_node(&0).unwrap();
+        let isolated = graph.find_node(&2).unwrap();
+        let dominators = graph.dominators(root);
+        assert_eq!(dominators.immediate_dominator(isolated), None);
+        assert!(dominators.dominators(isolated).next().is_none());
+    }
+}
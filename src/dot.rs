@@ -0,0 +1,131 @@
+use crate::graph::Graph;
+use std::fmt::{self, Display, Formatter};
+
+impl<T, E> Graph<T, E> {
+    /// Returns a wrapper that renders the graph in Graphviz [DOT] format without edge labels.
+    ///
+    /// This path works for any graph, including the default unweighted `Graph<T>`. Use
+    /// [`to_dot_weighted`](Self::to_dot_weighted) when the edge weights should appear as labels.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> Dot<T, E> {
+        self.to_dot_with(NodeLabel::Value)
+    }
+
+    /// Returns a [`Dot`] wrapper using the given node-labelling strategy and no edge labels.
+    pub fn to_dot_with(&self, node_label: NodeLabel) -> Dot<T, E> {
+        Dot {
+            graph: self,
+            node_label: node_label,
+            edge_labels: self.edges().map(|_| None).collect(),
+        }
+    }
+}
+
+impl<T, E: Display> Graph<T, E> {
+    /// Returns a [`Dot`] wrapper that labels each edge with its weight's `Display` output.
+    pub fn to_dot_weighted(&self) -> Dot<T, E> {
+        Dot {
+            graph: self,
+            node_label: NodeLabel::Value,
+            edge_labels: self
+                .edges()
+                .map(|edge| Some(escape(self.edge_value(edge))))
+                .collect(),
+        }
+    }
+}
+
+/// Selects what a [`Dot`] renders as each node's label.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NodeLabel {
+    /// Label each node with its `NodeIndex`.
+    Index,
+    /// Label each node with its value's `Display` representation.
+    Value,
+}
+
+/// A [`Display`] wrapper that renders a [`Graph`] as a Graphviz `digraph`.
+///
+/// Construct one with [`Graph::to_dot`], [`Graph::to_dot_with`], or [`Graph::to_dot_weighted`].
+pub struct Dot<'g, T, E> {
+    graph: &'g Graph<T, E>,
+    node_label: NodeLabel,
+    edge_labels: Vec<Option<String>>,
+}
+
+impl<'g, T: Display, E> Display for Dot<'g, T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {{")?;
+        for (index, value) in self.graph.nodes() {
+            match self.node_label {
+                NodeLabel::Index => writeln!(f, "    {} [label=\"{}\"];", index.0, index.0)?,
+                NodeLabel::Value => writeln!(f, "    {} [label=\"{}\"];", index.0, escape(value))?,
+            }
+        }
+        for edge in self.graph.edges() {
+            let (source, target) = self.graph.edge_endpoints(edge);
+            match &self.edge_labels[edge.0] {
+                Some(label) => writeln!(f, "    {} -> {} [label=\"{}\"];", source.0, target.0, label)?,
+                None => writeln!(f, "    {} -> {};", source.0, target.0)?,
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Escapes a value's `Display` output so it stays a valid DOT quoted string.
+fn escape<V: Display>(value: &V) -> String {
+    value
+        .to_string()
+        .chars()
+        .flat_map(|c| match c {
+            '"' => vec!['\\', '"'],
+            '\\' => vec!['\\', '\\'],
+            '\n' => vec!['\\', 'n'],
+            other => vec![other],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn dot_renders_unweighted_graph() {
+        let graph = graph!["a" -> "b"];
+        let dot = graph.to_dot().to_string();
+        assert_eq!(
+            dot,
+            "digraph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1;\n}"
+        );
+    }
+
+    #[test]
+    fn dot_weighted_renders_edge_labels() {
+        let graph: Graph<&str, u32> = graph!["a" -> "b" : 7];
+        let dot = graph.to_dot_weighted().to_string();
+        assert_eq!(
+            dot,
+            "digraph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1 [label=\"7\"];\n}"
+        );
+    }
+
+    #[test]
+    fn dot_labels_by_index() {
+        let graph = graph!["a" -> "b"];
+        let dot = graph.to_dot_with(NodeLabel::Index).to_string();
+        assert!(dot.contains("0 [label=\"0\"];"));
+        assert!(dot.contains("1 [label=\"1\"];"));
+    }
+
+    #[test]
+    fn dot_escapes_labels() {
+        let mut graph = Graph::<String, ()>::new();
+        graph.add_node("a\"b\\c\nd".to_string());
+        let dot = graph.to_dot().to_string();
+        assert!(dot.contains("label=\"a\\\"b\\\\c\\nd\""));
+    }
+}
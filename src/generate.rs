@@ -0,0 +1,93 @@
+use crate::graph::Graph;
+use rand::Rng;
+
+impl Graph<usize> {
+    /// Builds a graph from a whitespace-separated adjacency matrix.
+    ///
+    /// Each line is a row of `0`/`1` entries: row `i` column `j` being `1` means there is an edge
+    /// from node `i` to node `j`. The resulting graph has one node per row, valued `0..n`. Blank
+    /// lines are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an entry is not a valid integer.
+    pub fn from_adjacency_matrix(input: &str) -> Graph<usize> {
+        let rows: Vec<Vec<u8>> = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|entry| entry.parse().unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let mut graph = Graph::new();
+        let nodes: Vec<_> = (0..rows.len()).map(|index| graph.add_node(index)).collect();
+        for (source, row) in rows.iter().enumerate() {
+            for (target, &entry) in row.iter().enumerate() {
+                if entry != 0 {
+                    graph.add_edge(nodes[source], nodes[target], ());
+                }
+            }
+        }
+        graph
+    }
+
+    /// Generates an Erdős–Rényi random graph with `n` nodes valued `0..n`.
+    ///
+    /// Each of the `n * (n - 1)` possible directed edges (self-loops excluded) is added
+    /// independently with probability `p`, using `rng` as the source of randomness.
+    pub fn gnp<R: Rng>(n: usize, p: f64, rng: &mut R) -> Graph<usize> {
+        let mut graph = Graph::new();
+        let nodes: Vec<_> = (0..n).map(|index| graph.add_node(index)).collect();
+        for source in 0..n {
+            for target in 0..n {
+                if source != target && rng.gen::<f64>() < p {
+                    graph.add_edge(nodes[source], nodes[target], ());
+                }
+            }
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::thread_rng;
+
+    #[test]
+    fn adjacency_matrix_builds_edges() {
+        let graph = Graph::from_adjacency_matrix("0 1 0\n0 0 1\n0 0 0");
+        assert_eq!(graph.nodes().len(), 3);
+        assert_eq!(graph.edges().len(), 2);
+
+        let zero = graph.find_node(&0).unwrap();
+        let one = graph.find_node(&1).unwrap();
+        assert_eq!(graph.successors(zero).collect::<Vec<_>>(), [one]);
+    }
+
+    #[test]
+    fn adjacency_matrix_ignores_blank_lines() {
+        let graph = Graph::from_adjacency_matrix("0 1\n\n1 0\n");
+        assert_eq!(graph.nodes().len(), 2);
+        assert_eq!(graph.edges().len(), 2);
+    }
+
+    #[test]
+    fn gnp_with_certain_probability_is_complete() {
+        let mut rng = thread_rng();
+        let graph = Graph::gnp(4, 1.0, &mut rng);
+        assert_eq!(graph.nodes().len(), 4);
+        assert_eq!(graph.edges().len(), 4 * 3);
+    }
+
+    #[test]
+    fn gnp_with_zero_probability_is_empty() {
+        let mut rng = thread_rng();
+        let graph = Graph::gnp(5, 0.0, &mut rng);
+        assert_eq!(graph.nodes().len(), 5);
+        assert_eq!(graph.edges().len(), 0);
+    }
+}
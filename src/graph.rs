@@ -4,12 +4,12 @@
 ///
 /// [blog]: http://smallcultfollowing.com/babysteps/blog/2015/04/06/modeling-graphs-in-rust-using-vector-indices/
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct Graph<T> {
+pub struct Graph<T, E = ()> {
     pub(crate) nodes: Vec<Node<T>>,
-    pub(crate) edges: Vec<Edge>,
+    pub(crate) edges: Vec<Edge<E>>,
 }
 
-impl<T> Graph<T> {
+impl<T, E> Graph<T, E> {
     /// Creates a new empty graph.
     pub fn new() -> Self {
         Graph {
@@ -23,20 +23,30 @@ impl<T> Graph<T> {
         let index = NodeIndex(self.nodes.len());
         self.nodes.push(Node {
             value: value,
-            first_outgoing_edge: None,
+            first_edge: [None, None],
         });
         index
     }
 
-    /// Adds a new edge to the graph, and returns an `EdgeIndex` representing it.
-    pub fn add_edge(&mut self, source: NodeIndex, target: NodeIndex) -> EdgeIndex {
+    /// Adds a new edge to the graph carrying the weight `value`, and returns an `EdgeIndex`
+    /// representing it.
+    ///
+    /// The edge is spliced onto the head of `source`'s outgoing list and `target`'s incoming list,
+    /// so it can later be walked in either [`Direction`].
+    pub fn add_edge(&mut self, source: NodeIndex, target: NodeIndex, value: E) -> EdgeIndex {
         let edge_index = EdgeIndex(self.edges.len());
-        let node_data = &mut self.nodes[source.0];
+        let next_edge = [
+            self.nodes[source.0].first_edge[Direction::Outgoing.index()],
+            self.nodes[target.0].first_edge[Direction::Incoming.index()],
+        ];
         self.edges.push(Edge {
+            source: source,
             target: target,
-            next_outgoing_edge: node_data.first_outgoing_edge,
+            value: value,
+            next_edge: next_edge,
         });
-        node_data.first_outgoing_edge = Some(edge_index);
+        self.nodes[source.0].first_edge[Direction::Outgoing.index()] = Some(edge_index);
+        self.nodes[target.0].first_edge[Direction::Incoming.index()] = Some(edge_index);
         edge_index
     }
 
@@ -53,10 +63,37 @@ impl<T> Graph<T> {
         (0..self.edges.len()).map(EdgeIndex)
     }
 
+    /// Returns an iterator over the neighbors of a given node in the requested direction.
+    ///
+    /// Walking the [`Outgoing`] direction yields the node's successors; walking [`Incoming`] yields
+    /// its predecessors, so a traversal can walk the reversed graph without duplicating any
+    /// storage. [`bfs`](Self::bfs) and [`dfs`](Self::dfs) walk only the `Outgoing` direction.
+    ///
+    /// [`Outgoing`]: Direction::Outgoing
+    /// [`Incoming`]: Direction::Incoming
+    pub fn neighbors(&self, node: NodeIndex, direction: Direction) -> Neighbors<T, E> {
+        Neighbors {
+            graph: self,
+            direction: direction,
+            current_edge_index: self.nodes[node.0].first_edge[direction.index()],
+        }
+    }
+
     /// Returns an iterator over the successors of a given node.
-    pub fn successors(&self, source: NodeIndex) -> Successors<T> {
-        let first_outgoing_edge = self.nodes[source.0].first_outgoing_edge;
-        Successors {
+    pub fn successors(&self, source: NodeIndex) -> Neighbors<T, E> {
+        self.neighbors(source, Direction::Outgoing)
+    }
+
+    /// Returns an iterator over the predecessors of a given node.
+    pub fn predecessors(&self, target: NodeIndex) -> Neighbors<T, E> {
+        self.neighbors(target, Direction::Incoming)
+    }
+
+    /// Returns an iterator over the successors of a given node, paired with the weight of the edge
+    /// leading to each one.
+    pub fn successors_with_values(&self, source: NodeIndex) -> SuccessorsWithValues<T, E> {
+        let first_outgoing_edge = self.nodes[source.0].first_edge[Direction::Outgoing.index()];
+        SuccessorsWithValues {
             graph: self,
             current_edge_index: first_outgoing_edge,
         }
@@ -69,9 +106,23 @@ impl<T> Graph<T> {
     pub fn node_value_mut(&mut self, index: NodeIndex) -> &mut T {
         &mut self.nodes[index.0].value
     }
+
+    pub fn edge_value(&self, index: EdgeIndex) -> &E {
+        &self.edges[index.0].value
+    }
+
+    /// Returns the `(source, target)` endpoints of the given edge.
+    pub fn edge_endpoints(&self, index: EdgeIndex) -> (NodeIndex, NodeIndex) {
+        let edge = &self.edges[index.0];
+        (edge.source, edge.target)
+    }
+
+    pub fn edge_value_mut(&mut self, index: EdgeIndex) -> &mut E {
+        &mut self.edges[index.0].value
+    }
 }
 
-impl<T: PartialEq> Graph<T> {
+impl<T: PartialEq, E> Graph<T, E> {
     /// Finds the `NodeIndex` corresponding to the given value in the graph.
     ///
     /// If the graph does not contain `value`, this function returns `None`. If the graph contains
@@ -100,7 +151,7 @@ impl<T: PartialEq> std::iter::FromIterator<(T, T)> for Graph<T> {
             let target = graph
                 .find_node(&target)
                 .unwrap_or_else(|| graph.add_node(target));
-            graph.add_edge(source, target);
+            graph.add_edge(source, target, ());
         }
         graph
     }
@@ -109,27 +160,66 @@ impl<T: PartialEq> std::iter::FromIterator<(T, T)> for Graph<T> {
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct NodeIndex(pub(crate) usize);
 
+/// A direction in which a node's edges can be walked.
+///
+/// Each edge belongs to two linked lists: its source's [`Outgoing`] list and its target's
+/// [`Incoming`] list. The discriminant doubles as the index into the per-node `first_edge` and
+/// per-edge `next_edge` arrays.
+///
+/// [`Outgoing`]: Direction::Outgoing
+/// [`Incoming`]: Direction::Incoming
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    /// Edges leaving a node, leading to its successors.
+    Outgoing,
+    /// Edges entering a node, leading from its predecessors.
+    Incoming,
+}
+
+impl Direction {
+    /// Returns this direction's index into the two-element edge-list arrays.
+    pub(crate) fn index(self) -> usize {
+        match self {
+            Direction::Outgoing => 0,
+            Direction::Incoming => 1,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub(crate) struct Node<T> {
     value: T,
-    first_outgoing_edge: Option<EdgeIndex>,
+    first_edge: [Option<EdgeIndex>; 2],
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct EdgeIndex(pub(crate) usize);
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-pub(crate) struct Edge {
+pub(crate) struct Edge<E> {
+    source: NodeIndex,
     target: NodeIndex,
-    next_outgoing_edge: Option<EdgeIndex>,
+    value: E,
+    next_edge: [Option<EdgeIndex>; 2],
+}
+
+impl<E> Edge<E> {
+    /// Returns the endpoint reached by leaving this edge in `direction`.
+    fn endpoint(&self, direction: Direction) -> NodeIndex {
+        match direction {
+            Direction::Outgoing => self.target,
+            Direction::Incoming => self.source,
+        }
+    }
 }
 
-pub struct Successors<'g, T> {
-    graph: &'g Graph<T>,
+pub struct Neighbors<'g, T, E> {
+    graph: &'g Graph<T, E>,
+    direction: Direction,
     current_edge_index: Option<EdgeIndex>,
 }
 
-impl<'g, T> Iterator for Successors<'g, T> {
+impl<'g, T, E> Iterator for Neighbors<'g, T, E> {
     type Item = NodeIndex;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -137,8 +227,32 @@ impl<'g, T> Iterator for Successors<'g, T> {
             None => None,
             Some(edge_index) => {
                 let edge = &self.graph.edges[edge_index.0];
-                self.current_edge_index = edge.next_outgoing_edge;
-                Some(edge.target)
+                self.current_edge_index = edge.next_edge[self.direction.index()];
+                Some(edge.endpoint(self.direction))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.graph.nodes.len() - 1))
+    }
+}
+
+pub struct SuccessorsWithValues<'g, T, E> {
+    graph: &'g Graph<T, E>,
+    current_edge_index: Option<EdgeIndex>,
+}
+
+impl<'g, T, E> Iterator for SuccessorsWithValues<'g, T, E> {
+    type Item = (NodeIndex, &'g E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current_edge_index {
+            None => None,
+            Some(edge_index) => {
+                let edge = &self.graph.edges[edge_index.0];
+                self.current_edge_index = edge.next_edge[Direction::Outgoing.index()];
+                Some((edge.target, &edge.value))
             }
         }
     }
@@ -151,16 +265,18 @@ impl<'g, T> Iterator for Successors<'g, T> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use maplit::hashset;
+    use std::collections::HashSet;
 
     #[test]
     fn find_node_empty() {
-        let graph = Graph::new();
+        let graph = Graph::<i32, ()>::new();
         assert_eq!(graph.find_node(&1), None);
     }
 
     #[test]
     fn find_node_singleton() {
-        let mut graph = Graph::new();
+        let mut graph = Graph::<i32, ()>::new();
         let node = graph.add_node(1);
         assert_eq!(graph.find_node(&1), Some(node));
         assert_eq!(graph.find_node(&2), None);
@@ -168,7 +284,7 @@ mod test {
 
     #[test]
     fn find_node_many() {
-        let mut graph = Graph::new();
+        let mut graph = Graph::<i32, ()>::new();
         let one = graph.add_node(1);
         let two = graph.add_node(2);
         assert_eq!(graph.find_node(&1), Some(one));
@@ -178,11 +294,43 @@ mod test {
 
     #[test]
     fn test_add_multiple_nodes() {
-        let mut graph = Graph::new();
+        let mut graph = Graph::<i32, ()>::new();
         for _ in 0..5 {
             graph.find_node(&1).unwrap_or_else(|| graph.add_node(1));
         }
         assert!(graph.find_node(&1).is_some());
         assert_eq!(graph.nodes().len(), 1);
     }
+
+    #[test]
+    fn successors_and_predecessors() {
+        let graph = graph![0 -> 1, 2 -> 1];
+        let one = graph.find_node(&1).unwrap();
+        let zero = graph.find_node(&0).unwrap();
+        let two = graph.find_node(&2).unwrap();
+        assert_eq!(graph.successors(zero).collect::<Vec<_>>(), [one]);
+        assert!(graph.successors(one).next().is_none());
+        assert_eq!(
+            graph.predecessors(one).collect::<HashSet<_>>(),
+            hashset![zero, two]
+        );
+    }
+
+    #[test]
+    fn neighbors_match_direction() {
+        let graph = graph![0 -> 1, 1 -> 2];
+        let one = graph.find_node(&1).unwrap();
+        assert_eq!(
+            graph
+                .neighbors(one, Direction::Outgoing)
+                .collect::<Vec<_>>(),
+            graph.successors(one).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            graph
+                .neighbors(one, Direction::Incoming)
+                .collect::<Vec<_>>(),
+            graph.predecessors(one).collect::<Vec<_>>()
+        );
+    }
 }
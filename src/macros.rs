@@ -1,19 +1,40 @@
 #[macro_export]
 macro_rules! graph {
-    ( $( $x:tt -> $y:tt ),* $(,)? ) => {
+    // No more edges to process.
+    ( @edges $g:ident, ) => {};
+
+    // An edge carrying an explicit weight, e.g. `0 -> 1 : 5`.
+    ( @edges $g:ident, $x:tt -> $y:tt : $w:expr $(, $( $rest:tt )* )? ) => {
+        {
+            let x = $x;
+            let y = $y;
+            let x = $g.find_node(&x).unwrap_or_else(|| $g.add_node(x));
+            let y = $g.find_node(&y).unwrap_or_else(|| $g.add_node(y));
+            $g.add_edge(x, y, $w);
+        }
+        $( $crate::graph!(@edges $g, $( $rest )*); )?
+    };
+
+    // An edge with no weight defaults to the unit weight.
+    ( @edges $g:ident, $x:tt -> $y:tt $(, $( $rest:tt )* )? ) => {
+        {
+            let x = $x;
+            let y = $y;
+            let x = $g.find_node(&x).unwrap_or_else(|| $g.add_node(x));
+            let y = $g.find_node(&y).unwrap_or_else(|| $g.add_node(y));
+            $g.add_edge(x, y, ());
+        }
+        $( $crate::graph!(@edges $g, $( $rest )*); )?
+    };
+
+    ( $( $rest:tt )* ) => {
         {
             #[allow(unused_mut)]
             let mut g = Graph::new();
-            $(
-                let x = $x;
-                let y = $y;
-                let x = g.find_node(&x).unwrap_or_else(|| g.add_node(x));
-                let y = g.find_node(&y).unwrap_or_else(|| g.add_node(y));
-                g.add_edge(x, y);
-            )*
+            $crate::graph!(@edges g, $( $rest )*);
             g
         }
-    }
+    };
 }
 
 #[cfg(test)]